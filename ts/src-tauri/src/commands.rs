@@ -1,6 +1,9 @@
+pub mod inventory;
+
 use crate::models::*;
+use crate::state::Db;
 use tauri::ipc::Channel;
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, State};
 
 /// Simple command with no parameters
 #[tauri::command]
@@ -157,3 +160,95 @@ pub fn divide(a: f64, b: f64) -> Result<f64, String> {
         Ok(a / b)
     }
 }
+
+/// Command with injected AppHandle and State parameters
+#[tauri::command]
+pub fn record_visit(app: AppHandle, db: State<'_, Db>, page: String) -> Result<String, String> {
+    let mut visits = db.visits.lock().map_err(|e| e.to_string())?;
+    let count = visits.entry(page.clone()).or_insert(0);
+    *count += 1;
+
+    Ok(format!(
+        "{} visited {} time(s) in {}",
+        page,
+        count,
+        app.package_info().name
+    ))
+}
+
+/// Command returning tuple types
+#[tauri::command]
+pub fn get_store_location(name: String) -> StoreLocation {
+    StoreLocation {
+        name,
+        coordinates: (52.520008, 13.404954),
+        opening_hours: vec![
+            ("Mon-Fri".to_string(), "08:00-20:00".to_string()),
+            ("Sat".to_string(), "10:00-16:00".to_string()),
+        ],
+    }
+}
+
+/// Async command returning the same type as `get_user`
+#[tauri::command]
+pub async fn get_user_async(user_id: i32) -> User {
+    // Simulate a database lookup
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    get_user(user_id)
+}
+
+/// Command that emits events from a window and to a specific target
+#[tauri::command]
+pub fn send_notice(window: tauri::Window, app: AppHandle, message: String) -> Result<(), String> {
+    window
+        .emit(
+            "window-notice",
+            Notice {
+                source: window.label().to_string(),
+                message: message.clone(),
+            },
+        )
+        .map_err(|e| e.to_string())?;
+
+    app.emit_to(
+        "main",
+        "targeted-notice",
+        Notice {
+            source: "app".to_string(),
+            message,
+        },
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// Command with no return value
+#[tauri::command]
+pub fn noop() {}
+
+/// Command returning a unit Result
+#[tauri::command]
+pub fn try_noop() -> Result<(), String> {
+    Ok(())
+}
+
+/// Command with multiple struct parameters
+#[tauri::command]
+pub fn add_product_to_order(mut order: Order, product: Product) -> Result<Order, String> {
+    if order.products.iter().any(|p| p.id == product.id) {
+        return Err(format!("Product {} is already in the order", product.id));
+    }
+    order.total_amount += product.price;
+    order.products.push(product);
+    Ok(order)
+}
+
+/// Command returning platform-sized and 128-bit integers
+#[tauri::command]
+pub fn get_storage_stats() -> StorageStats {
+    StorageStats {
+        file_count: 42,
+        size_delta: -1024,
+        total_bytes: 8_589_934_592,
+        checksum: -7_340_032,
+    }
+}
@@ -1,9 +1,11 @@
 mod commands;
 mod models;
+mod state;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
   tauri::Builder::default()
+    .manage(state::Db::default())
     .setup(|app| {
       if cfg!(debug_assertions) {
         app.handle().plugin(
@@ -27,6 +29,15 @@ pub fn run() {
       commands::stream_logs,
       commands::monitor_system,
       commands::divide,
+      commands::record_visit,
+      commands::get_store_location,
+      commands::get_user_async,
+      commands::send_notice,
+      commands::noop,
+      commands::try_noop,
+      commands::inventory::restock_product,
+      commands::add_product_to_order,
+      commands::get_storage_stats,
     ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
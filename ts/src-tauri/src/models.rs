@@ -84,3 +84,30 @@ pub struct LogEntry {
     pub level: String,
     pub message: String,
 }
+
+/// Example with tuple types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreLocation {
+    pub name: String,
+    pub coordinates: (f64, f64),
+    pub opening_hours: Vec<(String, String)>,
+}
+
+/// Example for event payload sent from a window or to a target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Notice {
+    pub source: String,
+    pub message: String,
+}
+
+/// Example with platform-sized and 128-bit integers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageStats {
+    pub file_count: usize,
+    pub size_delta: isize,
+    pub total_bytes: u128,
+    pub checksum: i128,
+}
@@ -0,0 +1,8 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Example managed state, injected into commands via `State<Db>`
+#[derive(Debug, Default)]
+pub struct Db {
+    pub visits: Mutex<HashMap<String, u32>>,
+}
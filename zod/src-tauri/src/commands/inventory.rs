@@ -0,0 +1,8 @@
+use crate::models::*;
+
+/// Command defined in a submodule of `commands`
+#[tauri::command]
+pub fn restock_product(mut product: Product, quantity: u32) -> Product {
+    product.in_stock = Some(quantity > 0);
+    product
+}
@@ -58,8 +58,12 @@ pub enum PaymentMethod {
 pub struct Order {
     #[validate(length(min = 1, message = "Order ID cannot be empty"))]
     pub order_id: String,
+    #[validate(nested)]
     pub user: User,
-    #[validate(length(min = 1, message = "Order must contain at least one product"))]
+    #[validate(
+        length(min = 1, message = "Order must contain at least one product"),
+        nested
+    )]
     pub products: Vec<Product>,
     pub status: OrderStatus,
     pub payment_method: PaymentMethod,
@@ -103,3 +107,33 @@ pub struct LogEntry {
     #[validate(length(min = 1, max = 500, message = "Log message must be 1-500 characters"))]
     pub message: String,
 }
+
+/// Example with tuple types and validations
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreLocation {
+    #[validate(length(min = 1, message = "Store name cannot be empty"))]
+    pub name: String,
+    pub coordinates: (f64, f64),
+    pub opening_hours: Vec<(String, String)>,
+}
+
+/// Example for event payload sent from a window or to a target with validations
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct Notice {
+    pub source: String,
+    #[validate(length(min = 1, message = "Notice message cannot be empty"))]
+    pub message: String,
+}
+
+/// Example with platform-sized and 128-bit integers and validations
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageStats {
+    #[validate(range(max = 10000, message = "File count cannot exceed 10000"))]
+    pub file_count: usize,
+    pub size_delta: isize,
+    pub total_bytes: u128,
+    pub checksum: i128,
+}